@@ -1,13 +1,17 @@
+mod render_layer_registry;
 mod render_layers;
 
+pub use render_layer_registry::*;
 pub use render_layers::*;
 
 use bevy_app::{Plugin, PostUpdate};
 use bevy_asset::{Assets, Handle};
+use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::prelude::*;
 use bevy_hierarchy::{Children, Parent};
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 use bevy_transform::{components::GlobalTransform, TransformSystem};
+use bevy_utils::HashMap;
 use std::cell::Cell;
 use thread_local::ThreadLocal;
 
@@ -191,6 +195,31 @@ impl VisibleEntities {
     }
 }
 
+/// The [`RenderLayers`] of cameras that don't have a [`RenderLayers`] component.
+///
+/// Defaults to layer `0`, the layer of entities without [`RenderLayers`]. Setting it to
+/// [`RenderLayers::none`] makes such cameras see nothing until they are given [`RenderLayers`].
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq, Deref, DerefMut, Reflect)]
+#[reflect(Resource, Default, PartialEq)]
+pub struct DefaultCameraRenderLayers(pub RenderLayers);
+
+/// The layers that have at least one visible entity, for each camera.
+///
+/// For each camera this is the union of the [`RenderLayers`] of its [`VisibleEntities`],
+/// intersected with the camera's own [`RenderLayers`].
+///
+/// This resource isn't added by default. Once it's inserted, it is rebuilt every frame by
+/// [`update_visible_layers_per_camera`] in [`VisibilitySystems::UpdateVisibleLayersPerCamera`].
+///
+/// ```
+/// # use bevy_app::App;
+/// # use bevy_render::view::VisibleLayersPerCamera;
+/// # let mut app = App::new();
+/// app.init_resource::<VisibleLayersPerCamera>();
+/// ```
+#[derive(Resource, Debug, Default, Clone, Deref)]
+pub struct VisibleLayersPerCamera(HashMap<Entity, RenderLayers>);
+
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
 pub enum VisibilitySystems {
     CalculateBounds,
@@ -202,6 +231,9 @@ pub enum VisibilitySystems {
     /// Label for the [`check_visibility()`] system updating each frame the [`ComputedVisibility`]
     /// of each entity and the [`VisibleEntities`] of each view.
     CheckVisibility,
+    /// Label for the [`update_visible_layers_per_camera()`] system updating
+    /// [`VisibleLayersPerCamera`], if that resource exists.
+    UpdateVisibleLayersPerCamera,
 }
 
 pub struct VisibilityPlugin;
@@ -210,7 +242,9 @@ impl Plugin for VisibilityPlugin {
     fn build(&self, app: &mut bevy_app::App) {
         use VisibilitySystems::*;
 
-        app
+        app.register_type::<DefaultCameraRenderLayers>()
+            .init_resource::<DefaultCameraRenderLayers>()
+            .init_resource::<RenderLayerRegistry>()
             // We add an AABB component in CalculateBounds, which must be ready on the same frame.
             .add_systems(PostUpdate, apply_deferred.in_set(CalculateBoundsFlush))
             .configure_set(PostUpdate, CalculateBoundsFlush.after(CalculateBounds))
@@ -248,6 +282,10 @@ impl Plugin for VisibilityPlugin {
                         .after(UpdateProjectionFrusta)
                         .after(VisibilityPropagate)
                         .after(TransformSystem::TransformPropagate),
+                    update_visible_layers_per_camera
+                        .in_set(UpdateVisibleLayersPerCamera)
+                        .after(CheckVisibility)
+                        .run_if(resource_exists::<VisibleLayersPerCamera>()),
                 ),
             );
     }
@@ -349,6 +387,7 @@ fn propagate_recursive(
 /// for that view.
 pub fn check_visibility(
     mut thread_queues: Local<ThreadLocal<Cell<Vec<Entity>>>>,
+    default_camera_layers: Res<DefaultCameraRenderLayers>,
     mut view_query: Query<(&mut VisibleEntities, &Frustum, Option<&RenderLayers>), With<Camera>>,
     mut visible_aabb_query: Query<(
         Entity,
@@ -364,7 +403,7 @@ pub fn check_visibility(
     >,
 ) {
     for (mut visible_entities, frustum, maybe_view_mask) in &mut view_query {
-        let view_mask = maybe_view_mask.copied().unwrap_or_default();
+        let view_mask = maybe_view_mask.copied().unwrap_or(**default_camera_layers);
 
         visible_entities.entities.clear();
         visible_aabb_query.par_iter_mut().for_each(
@@ -439,6 +478,43 @@ pub fn check_visibility(
     }
 }
 
+/// System updating [`VisibleLayersPerCamera`] from the [`VisibleEntities`] of each camera.
+pub fn update_visible_layers_per_camera(
+    mut visible_layers: ResMut<VisibleLayersPerCamera>,
+    default_camera_layers: Res<DefaultCameraRenderLayers>,
+    view_query: Query<(Entity, &VisibleEntities, Option<&RenderLayers>), With<Camera>>,
+    entity_query: Query<Option<&RenderLayers>>,
+) {
+    visible_layers.0.clear();
+    for (camera, visible_entities, maybe_view_mask) in &view_query {
+        let view_mask = maybe_view_mask.copied().unwrap_or(**default_camera_layers);
+        let entity_masks = visible_entities
+            .iter()
+            .filter_map(|entity| entity_query.get(*entity).ok())
+            .fold(RenderLayers::none(), |union, maybe_entity_mask| {
+                union | maybe_entity_mask.copied().unwrap_or_default()
+            });
+        visible_layers.0.insert(camera, entity_masks & view_mask);
+    }
+}
+
+/// Computes the layers that entities are on but a camera with `camera_layers` doesn't see, such
+/// as for listing unused layers in an editor.
+///
+/// Entities without [`RenderLayers`] are on layer `0`, so `None` is treated as the default
+/// [`RenderLayers`]. A camera without [`RenderLayers`] uses [`DefaultCameraRenderLayers`].
+pub fn unseen_layers<'a>(
+    camera_layers: RenderLayers,
+    entity_layers: impl IntoIterator<Item = Option<&'a RenderLayers>>,
+) -> RenderLayers {
+    let entity_layers = entity_layers
+        .into_iter()
+        .fold(RenderLayers::none(), |union, layers| {
+            union | layers.copied().unwrap_or_default()
+        });
+    entity_layers & RenderLayers::from_bits(!camera_layers.bits())
+}
+
 #[cfg(test)]
 mod test {
     use bevy_app::prelude::*;
@@ -651,4 +727,113 @@ mod test {
         assert_eq!(1, mem::size_of::<Visibility>());
         assert_eq!(1, mem::size_of::<Option<Visibility>>());
     }
+
+    #[test]
+    fn unseen_layers_of_camera() {
+        let mut world = World::new();
+        let camera = world
+            .spawn((Camera::default(), RenderLayers::layer(0)))
+            .id();
+        world.spawn_empty();
+        world.spawn(RenderLayers::layer(3));
+        world.spawn(RenderLayers::from_layers(&[0, 9]));
+
+        let mut entities = world.query_filtered::<Option<&RenderLayers>, Without<Camera>>();
+        let camera_layers = *world.get::<RenderLayers>(camera).unwrap();
+        assert_eq!(
+            unseen_layers(camera_layers, entities.iter(&world)),
+            RenderLayers::from_layers(&[3, 9])
+        );
+        assert_eq!(
+            unseen_layers(RenderLayers::all(), entities.iter(&world)),
+            RenderLayers::none()
+        );
+    }
+
+    #[test]
+    fn visible_layers_per_camera() {
+        let mut app = App::new();
+        app.init_resource::<DefaultCameraRenderLayers>()
+            .init_resource::<VisibleLayersPerCamera>()
+            .add_systems(
+                Update,
+                (
+                    visibility_propagate_system,
+                    check_visibility,
+                    update_visible_layers_per_camera,
+                )
+                    .chain(),
+            );
+
+        let camera = app
+            .world
+            .spawn((
+                Camera::default(),
+                Frustum::default(),
+                VisibleEntities::default(),
+                RenderLayers::from_layers(&[0, 3]),
+            ))
+            .id();
+        for layers in [&[0][..], &[3], &[9], &[3, 9]] {
+            app.world.spawn((
+                Visibility::default(),
+                ComputedVisibility::default(),
+                RenderLayers::from_layers(layers),
+            ));
+        }
+
+        app.update();
+
+        // The entity on `[3, 9]` is visible, but the camera doesn't see layer `9`
+        let visible_layers = app.world.resource::<VisibleLayersPerCamera>();
+        assert_eq!(visible_layers.len(), 1);
+        assert_eq!(
+            visible_layers.get(&camera),
+            Some(&RenderLayers::from_layers(&[0, 3]))
+        );
+    }
+
+    #[test]
+    fn default_camera_render_layers() {
+        let mut app = App::new();
+        app.insert_resource(DefaultCameraRenderLayers(RenderLayers::none()))
+            .add_systems(
+                Update,
+                (visibility_propagate_system, check_visibility).chain(),
+            );
+
+        let camera = app
+            .world
+            .spawn((
+                Camera::default(),
+                Frustum::default(),
+                VisibleEntities::default(),
+            ))
+            .id();
+        let entity = app
+            .world
+            .spawn((Visibility::default(), ComputedVisibility::default()))
+            .id();
+
+        // Without `RenderLayers` the camera sees nothing
+        app.update();
+        assert!(app.world.get::<VisibleEntities>(camera).unwrap().is_empty());
+
+        // Until it's given layers
+        app.world.entity_mut(camera).insert(RenderLayers::layer(0));
+        app.update();
+        assert_eq!(
+            app.world.get::<VisibleEntities>(camera).unwrap().entities,
+            vec![entity]
+        );
+
+        // The default sees layer `0`, like entities without `RenderLayers`
+        app.world.entity_mut(camera).remove::<RenderLayers>();
+        *app.world.resource_mut::<DefaultCameraRenderLayers>() = Default::default();
+        app.update();
+        assert_eq!(
+            app.world.get::<VisibleEntities>(camera).unwrap().entities,
+            vec![entity]
+        );
+    }
 }
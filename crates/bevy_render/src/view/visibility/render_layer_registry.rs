@@ -0,0 +1,91 @@
+use bevy_ecs::system::Resource;
+use bevy_utils::HashMap;
+
+use super::{Layer, RenderLayers};
+
+/// Maps names to rendering [`Layer`]s, so separate parts of an app can share layers without
+/// agreeing on raw indices.
+///
+/// Layers are allocated in registration order starting from layer `1`. Layer `0` is never
+/// handed out, because it is the [`Default`] layer of every entity without [`RenderLayers`].
+///
+/// ```
+/// # use bevy_render::view::{RenderLayerRegistry, RenderLayers};
+/// let mut registry = RenderLayerRegistry::default();
+/// let minimap = registry.register("minimap");
+///
+/// assert_eq!(registry.get("minimap"), Some(minimap));
+/// let minimap_camera_layers = RenderLayers::layer(minimap);
+/// ```
+#[derive(Resource, Debug, Default, Clone)]
+pub struct RenderLayerRegistry {
+    layers: HashMap<String, Layer>,
+}
+
+impl RenderLayerRegistry {
+    /// Returns the layer registered under `name`, allocating the next free layer if the name is
+    /// new.
+    ///
+    /// # Panics
+    /// Panics if `name` is new and all [`TOTAL_LAYERS`](RenderLayers::TOTAL_LAYERS) layers
+    /// besides layer `0` are already registered.
+    pub fn register(&mut self, name: impl Into<String>) -> Layer {
+        let name = name.into();
+        if let Some(layer) = self.layers.get(&name) {
+            return *layer;
+        }
+        let layer = self.layers.len() + 1;
+        assert!(
+            layer < RenderLayers::TOTAL_LAYERS,
+            "No free render layer left to register {name:?}"
+        );
+        let layer = layer as Layer;
+        self.layers.insert(name, layer);
+        layer
+    }
+
+    /// Returns the layer registered under `name`, if any.
+    pub fn get(&self, name: &str) -> Option<Layer> {
+        self.layers.get(name).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RenderLayerRegistry;
+    use crate::view::RenderLayers;
+
+    #[test]
+    fn register_and_get() {
+        let mut registry = RenderLayerRegistry::default();
+        assert_eq!(registry.get("minimap"), None);
+
+        let minimap = registry.register("minimap");
+        assert_eq!(minimap, 1);
+        assert_eq!(registry.get("minimap"), Some(minimap));
+        assert_eq!(registry.register("minimap"), minimap);
+        assert_eq!(registry.get("ui"), None);
+    }
+
+    #[test]
+    fn allocations_do_not_collide() {
+        let mut registry = RenderLayerRegistry::default();
+        let mut layers = RenderLayers::none();
+        for i in 1..RenderLayers::TOTAL_LAYERS {
+            let layer = registry.register(format!("layer {i}"));
+            assert_ne!(layer, 0);
+            assert!(!layers.contains_any(&[layer]));
+            layers = layers.with(layer);
+        }
+        assert_eq!(layers, RenderLayers::all().without(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn register_panics_when_full() {
+        let mut registry = RenderLayerRegistry::default();
+        for i in 0..RenderLayers::TOTAL_LAYERS {
+            registry.register(format!("layer {i}"));
+        }
+    }
+}
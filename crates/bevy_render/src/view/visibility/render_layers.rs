@@ -1,6 +1,10 @@
 use bevy_ecs::prelude::{Component, ReflectComponent};
 use bevy_reflect::std_traits::ReflectDefault;
-use bevy_reflect::Reflect;
+use bevy_reflect::{Reflect, ReflectDeserialize, ReflectSerialize};
+use serde::{Deserialize, Serialize};
+use std::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Bound, Range, RangeBounds,
+};
 
 type LayerMask = u32;
 
@@ -20,8 +24,10 @@ pub type Layer = u8;
 /// An entity with this component without any layers is invisible.
 ///
 /// Entities without this component belong to layer `0`.
-#[derive(Component, Copy, Clone, Reflect, PartialEq, Eq, PartialOrd, Ord)]
-#[reflect(Component, Default, PartialEq)]
+#[derive(
+    Component, Copy, Clone, Reflect, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[reflect(Component, Default, PartialEq, Hash, Serialize, Deserialize)]
 pub struct RenderLayers(LayerMask);
 
 impl std::fmt::Debug for RenderLayers {
@@ -32,12 +38,92 @@ impl std::fmt::Debug for RenderLayers {
     }
 }
 
+/// Lists the layers in braces, collapsing runs of consecutive layers, e.g. `{0-3,7}`.
+impl std::fmt::Display for RenderLayers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{")?;
+        let mut layers = self.iter().peekable();
+        let mut first = true;
+        while let Some(start) = layers.next() {
+            let mut end = start;
+            while layers.next_if_eq(&(end + 1)).is_some() {
+                end += 1;
+            }
+            if !first {
+                write!(f, ",")?;
+            }
+            first = false;
+            if start == end {
+                write!(f, "{start}")?;
+            } else {
+                write!(f, "{start}-{end}")?;
+            }
+        }
+        write!(f, "}}")
+    }
+}
+
 impl std::iter::FromIterator<Layer> for RenderLayers {
     fn from_iter<T: IntoIterator<Item = Layer>>(i: T) -> Self {
         i.into_iter().fold(Self::none(), |mask, g| mask.with(g))
     }
 }
 
+/// Duplicate layers are only added once.
+///
+/// # Panics
+/// Panics when any layer is greater than `TOTAL_LAYERS - 1`.
+impl From<Vec<Layer>> for RenderLayers {
+    fn from(layers: Vec<Layer>) -> Self {
+        layers.into_iter().collect()
+    }
+}
+
+/// The layers in either `RenderLayers`.
+impl BitOr for RenderLayers {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        RenderLayers(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for RenderLayers {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// The layers in both `RenderLayers`.
+impl BitAnd for RenderLayers {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        RenderLayers(self.0 & rhs.0)
+    }
+}
+
+impl BitAndAssign for RenderLayers {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.0 &= rhs.0;
+    }
+}
+
+/// The layers in exactly one of the `RenderLayers`.
+impl BitXor for RenderLayers {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        RenderLayers(self.0 ^ rhs.0)
+    }
+}
+
+impl BitXorAssign for RenderLayers {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        self.0 ^= rhs.0;
+    }
+}
+
 /// Defaults to containing to layer `0`, the first layer.
 impl Default for RenderLayers {
     fn default() -> Self {
@@ -64,11 +150,34 @@ impl RenderLayers {
         RenderLayers(0)
     }
 
+    /// Create a `RenderLayers` from its raw bitmask, where bit `n` is set when layer `n` is.
+    ///
+    /// This is the inverse of [`bits`](RenderLayers::bits).
+    pub const fn from_bits(bits: u32) -> Self {
+        RenderLayers(bits)
+    }
+
+    /// Get the raw bitmask, where bit `n` is set when layer `n` is.
+    pub const fn bits(&self) -> u32 {
+        self.0
+    }
+
     /// Create a `RenderLayers` from a list of layers.
     pub fn from_layers(layers: &[Layer]) -> Self {
         layers.iter().copied().collect()
     }
 
+    /// Create a `RenderLayers` belonging to every layer in `range`.
+    ///
+    /// An unbounded start begins at layer `0`, and an unbounded end includes every layer up to
+    /// `TOTAL_LAYERS - 1`.
+    ///
+    /// # Panics
+    /// Panics when `range` extends past `TOTAL_LAYERS - 1`, or starts after it ends.
+    pub fn from_range(range: impl RangeBounds<Layer>) -> Self {
+        RenderLayers(Self::range_mask(range))
+    }
+
     /// Add the given layer.
     ///
     /// This may be called multiple times to allow an entity to belong
@@ -94,13 +203,205 @@ impl RenderLayers {
         self
     }
 
+    /// Returns `true` if no layers are set.
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Add all of the given layers.
+    ///
+    /// # Panics
+    /// Panics when any layer is greater than `TOTAL_LAYERS - 1`.
+    #[must_use]
+    pub fn with_layers(self, layers: &[Layer]) -> Self {
+        self | Self::from_layers(layers)
+    }
+
+    /// Removes all of the given layers.
+    ///
+    /// # Panics
+    /// Panics when any layer is greater than `TOTAL_LAYERS - 1`.
+    #[must_use]
+    pub fn without_layers(self, layers: &[Layer]) -> Self {
+        self & RenderLayers(!Self::from_layers(layers).0)
+    }
+
+    /// Removes every layer in `range`.
+    ///
+    /// An unbounded start begins at layer `0`, and an unbounded end includes every layer up to
+    /// `TOTAL_LAYERS - 1`.
+    ///
+    /// # Panics
+    /// Panics when `range` extends past `TOTAL_LAYERS - 1`, or starts after it ends.
+    #[must_use]
+    pub fn without_range(self, range: impl RangeBounds<Layer>) -> Self {
+        RenderLayers(self.0 & !Self::range_mask(range))
+    }
+
+    /// Adds the given layer if it's missing, or removes it if it's present.
+    ///
+    /// # Panics
+    /// Panics when called with a layer greater than `TOTAL_LAYERS - 1`.
+    #[must_use]
+    pub const fn toggle(mut self, layer: Layer) -> Self {
+        assert!((layer as usize) < Self::TOTAL_LAYERS);
+        self.0 ^= 1 << layer;
+        self
+    }
+
+    /// Keeps only the layers for which `f` returns `true`, removing the rest.
+    ///
+    /// `f` is called once for every layer that is set, in ascending order.
+    pub fn retain(&mut self, mut f: impl FnMut(Layer) -> bool) {
+        let mut layers = *self;
+        while let Some(layer) = layers.pop_lowest() {
+            if !f(layer) {
+                *self = self.without(layer);
+            }
+        }
+    }
+
+    /// Get the number of layers that are set.
+    pub const fn count(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
     /// Get an iterator of the layers.
-    pub fn iter(&self) -> impl Iterator<Item = Layer> {
+    ///
+    /// Layers are always yielded in ascending order. Use [`Iterator::rev`] to walk them from the
+    /// highest layer down.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Layer> {
         let total: Layer = std::convert::TryInto::try_into(Self::TOTAL_LAYERS).unwrap();
         let mask = *self;
         (0..total).filter(move |g| RenderLayers::layer(*g).intersects(&mask))
     }
 
+    /// Collect the layers into a `Vec`, in ascending order.
+    pub fn to_vec(&self) -> Vec<Layer> {
+        self.iter().collect()
+    }
+
+    /// Get an iterator over every layer in `range`, paired with whether the layer is set.
+    ///
+    /// Unlike [`iter`](RenderLayers::iter), this also yields the layers that are not set.
+    ///
+    /// # Panics
+    /// Panics when `range` extends past `TOTAL_LAYERS`, or starts after it ends.
+    pub fn states(&self, range: Range<Layer>) -> impl Iterator<Item = (Layer, bool)> {
+        assert!(range.start <= range.end && (range.end as usize) <= Self::TOTAL_LAYERS);
+        let mask = *self;
+        range.map(move |g| (g, RenderLayers::layer(g).intersects(&mask)))
+    }
+
+    /// Count the layers in `range` that are set.
+    ///
+    /// # Panics
+    /// Panics when `range` extends past `TOTAL_LAYERS`, or starts after it ends.
+    pub fn count_in_range(&self, range: Range<Layer>) -> usize {
+        (self.0 & Self::range_mask(range)).count_ones() as usize
+    }
+
+    /// Get the mask with every bit in `range` set.
+    ///
+    /// # Panics
+    /// Panics when `range` extends past `TOTAL_LAYERS`, or starts after it ends.
+    fn range_mask(range: impl RangeBounds<Layer>) -> LayerMask {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start as usize,
+            Bound::Excluded(&start) => start as usize + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end as usize + 1,
+            Bound::Excluded(&end) => end as usize,
+            Bound::Unbounded => {
+                // Every layer from `start` on, so `start` has to be a layer
+                assert!(start < Self::TOTAL_LAYERS);
+                Self::TOTAL_LAYERS
+            }
+        };
+        assert!(start <= end && end <= Self::TOTAL_LAYERS);
+        // Computed in 64 bits so a range ending at `TOTAL_LAYERS` doesn't overflow the shift
+        ((1u64 << end) - (1u64 << start)) as LayerMask
+    }
+
+    /// Get the lowest layer, or `None` if there are no layers.
+    pub const fn lowest(&self) -> Option<Layer> {
+        if self.0 == 0 {
+            None
+        } else {
+            Some(self.0.trailing_zeros() as Layer)
+        }
+    }
+
+    /// Get the highest layer, or `None` if there are no layers.
+    pub const fn highest(&self) -> Option<Layer> {
+        if self.0 == 0 {
+            None
+        } else {
+            Some((LayerMask::BITS - 1 - self.0.leading_zeros()) as Layer)
+        }
+    }
+
+    /// Removes and returns the lowest layer, or `None` if there are no layers.
+    ///
+    /// This allows draining the layers in ascending order with
+    /// `while let Some(layer) = layers.pop_lowest() { .. }`.
+    pub fn pop_lowest(&mut self) -> Option<Layer> {
+        let layer = self.lowest()?;
+        // Clear the lowest set bit
+        self.0 &= self.0 - 1;
+        Some(layer)
+    }
+
+    /// Get an iterator of the layers in both `self` and `other`, in ascending order.
+    pub fn intersection(&self, other: &RenderLayers) -> impl Iterator<Item = Layer> {
+        (*self & *other).iter()
+    }
+
+    /// Get an iterator of the layers in `self` that are not in `other`, in ascending order.
+    pub fn difference(&self, other: &RenderLayers) -> impl Iterator<Item = Layer> {
+        (*self & RenderLayers(!other.0)).iter()
+    }
+
+    /// Get an iterator of the layers in exactly one of `self` and `other`, in ascending order.
+    pub fn symmetric_difference(&self, other: &RenderLayers) -> impl Iterator<Item = Layer> {
+        (*self ^ *other).iter()
+    }
+
+    /// Returns `true` if every one of `layers` is set.
+    ///
+    /// This is `true` for an empty slice.
+    ///
+    /// # Panics
+    /// Panics when any layer is greater than `TOTAL_LAYERS - 1`.
+    pub fn contains_all(&self, layers: &[Layer]) -> bool {
+        let mask = Self::from_layers(layers);
+        (self.0 & mask.0) == mask.0
+    }
+
+    /// Returns `true` if any of `layers` is set.
+    ///
+    /// This is `false` for an empty slice.
+    ///
+    /// # Panics
+    /// Panics when any layer is greater than `TOTAL_LAYERS - 1`.
+    pub fn contains_any(&self, layers: &[Layer]) -> bool {
+        self.intersects(&Self::from_layers(layers))
+    }
+
+    /// Returns `true` if every layer in `self` is also in `other`.
+    ///
+    /// A `RenderLayers` with no layers is a subset of every `RenderLayers`.
+    pub const fn is_subset(&self, other: &RenderLayers) -> bool {
+        (self.0 & !other.0) == 0
+    }
+
+    /// Returns `true` if every layer in `other` is also in `self`.
+    pub const fn is_superset(&self, other: &RenderLayers) -> bool {
+        other.is_subset(self)
+    }
+
     /// Determine if a `RenderLayers` intersects another.
     ///
     /// `RenderLayers`s intersect if they share any common layers.
@@ -115,6 +416,7 @@ impl RenderLayers {
 #[cfg(test)]
 mod rendering_mask_tests {
     use super::{Layer, RenderLayers};
+    use bevy_utils::HashMap;
 
     #[test]
     fn rendering_mask_sanity() {
@@ -178,4 +480,397 @@ mod rendering_mask_tests {
             "from_layers and from_iter are equivalent"
         );
     }
+
+    #[test]
+    fn states_include_unset_layers() {
+        assert_eq!(
+            RenderLayers::from_layers(&[1, 3])
+                .states(0..4)
+                .collect::<Vec<_>>(),
+            vec![(0, false), (1, true), (2, false), (3, true)]
+        );
+        assert_eq!(
+            RenderLayers::from_layers(&[31])
+                .states(30..32)
+                .collect::<Vec<_>>(),
+            vec![(30, false), (31, true)]
+        );
+        assert_eq!(RenderLayers::all().states(5..5).count(), 0);
+    }
+
+    #[test]
+    fn pop_lowest_drains_in_order() {
+        let mut layers = RenderLayers::from_layers(&[2, 5, 9, 31]);
+        let mut popped = Vec::new();
+        while let Some(layer) = layers.pop_lowest() {
+            popped.push(layer);
+        }
+        assert_eq!(popped, vec![2, 5, 9, 31]);
+        assert_eq!(layers, RenderLayers::none());
+        assert_eq!(layers.pop_lowest(), None);
+    }
+
+    #[test]
+    fn vec_roundtrip() {
+        assert_eq!(RenderLayers::from(vec![0, 3, 9]).to_vec(), vec![0, 3, 9]);
+        assert_eq!(
+            RenderLayers::from(vec![9, 0, 3, 9, 0]).to_vec(),
+            vec![0, 3, 9],
+            "duplicates are removed and layers are sorted"
+        );
+        assert!(RenderLayers::from(Vec::new()).to_vec().is_empty());
+    }
+
+    #[test]
+    fn iter_is_ascending() {
+        let layers = RenderLayers::none().with(31).with(2).with(18).with(17);
+        assert_eq!(layers.iter().collect::<Vec<_>>(), vec![2, 17, 18, 31]);
+        assert_eq!(
+            RenderLayers::all().iter().collect::<Vec<_>>(),
+            (0..32).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn count_in_range() {
+        let layers = RenderLayers::from_layers(&[0, 5, 15, 16, 31]);
+        assert_eq!(layers.count_in_range(0..16), 3);
+        assert_eq!(layers.count_in_range(16..32), 2);
+        assert_eq!(layers.count_in_range(0..32), 5);
+        assert_eq!(layers.count_in_range(1..5), 0);
+        assert_eq!(layers.count_in_range(5..5), 0);
+        assert_eq!(RenderLayers::all().count_in_range(0..32), 32);
+    }
+
+    #[test]
+    fn bitwise_operators() {
+        let a = RenderLayers::from_layers(&[0, 15, 16, 31]);
+        let b = RenderLayers::from_layers(&[1, 16, 31]);
+        assert_eq!(a | b, RenderLayers::from_layers(&[0, 1, 15, 16, 31]));
+        assert_eq!(a & b, RenderLayers::from_layers(&[16, 31]));
+        assert_eq!(a ^ b, RenderLayers::from_layers(&[0, 1, 15]));
+        assert_eq!(a & RenderLayers::none(), RenderLayers::none());
+
+        let mut c = a;
+        c |= b;
+        assert_eq!(c, a | b);
+        let mut c = a;
+        c &= b;
+        assert_eq!(c, a & b);
+        let mut c = a;
+        c ^= b;
+        assert_eq!(c, a ^ b);
+        c ^= b;
+        assert_eq!(c, a, "xor is its own inverse");
+    }
+
+    #[test]
+    fn differences() {
+        let a = RenderLayers::from_layers(&[0, 3, 16, 31]);
+        let b = RenderLayers::from_layers(&[3, 9, 31]);
+        assert_eq!(a.difference(&b).collect::<Vec<_>>(), vec![0, 16]);
+        assert_eq!(b.difference(&a).collect::<Vec<_>>(), vec![9]);
+        assert_eq!(
+            a.symmetric_difference(&b).collect::<Vec<_>>(),
+            vec![0, 9, 16]
+        );
+        assert_eq!(a.difference(&a).count(), 0);
+        assert_eq!(
+            a.difference(&RenderLayers::none()).collect::<Vec<_>>(),
+            a.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(RenderLayers::none().is_empty());
+        assert!(!RenderLayers::default().is_empty());
+        assert!(!RenderLayers::layer(31).is_empty());
+        assert!(RenderLayers::layer(31).without(31).is_empty());
+    }
+
+    #[test]
+    fn count() {
+        let layers = RenderLayers::from_layers(&[0, 7, 8, 16, 31]);
+        assert_eq!(layers.count(), 5);
+        assert_eq!(layers.count(), layers.iter().count());
+        assert_eq!(RenderLayers::none().count(), 0);
+        assert_eq!(RenderLayers::all().count(), RenderLayers::TOTAL_LAYERS);
+    }
+
+    #[test]
+    fn from_range() {
+        assert_eq!(
+            RenderLayers::from_range(4..=12),
+            RenderLayers::from_layers(&[4, 5, 6, 7, 8, 9, 10, 11, 12])
+        );
+        assert_eq!(
+            RenderLayers::from_range(14..18),
+            RenderLayers::from_layers(&[14, 15, 16, 17])
+        );
+        assert_eq!(
+            RenderLayers::from_range(..3),
+            RenderLayers::from_layers(&[0, 1, 2])
+        );
+        assert_eq!(
+            RenderLayers::from_range(30..),
+            RenderLayers::from_layers(&[30, 31])
+        );
+        assert_eq!(RenderLayers::from_range(..), RenderLayers::all());
+        assert_eq!(RenderLayers::from_range(..=31), RenderLayers::all());
+        assert!(RenderLayers::from_range(5..5).is_empty());
+        assert!(RenderLayers::from_range(32..32).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_range_past_last_layer() {
+        let _ = RenderLayers::from_range(0..=32);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_range_starting_past_last_layer() {
+        let _ = RenderLayers::from_range(32..);
+    }
+
+    #[test]
+    #[should_panic]
+    fn without_range_starting_past_last_layer() {
+        let _ = RenderLayers::all().without_range(40..);
+    }
+
+    #[test]
+    #[should_panic]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn count_in_range_starting_after_end() {
+        let _ = RenderLayers::all().count_in_range(40..32);
+    }
+
+    #[test]
+    #[should_panic]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn states_starting_after_end() {
+        let _ = RenderLayers::all().states(8..4);
+    }
+
+    #[test]
+    fn toggle() {
+        let layers = RenderLayers::from_layers(&[0, 5]);
+        assert_eq!(layers.toggle(31), RenderLayers::from_layers(&[0, 5, 31]));
+        assert_eq!(layers.toggle(5), RenderLayers::layer(0));
+        assert_eq!(layers.toggle(31).toggle(31), layers);
+        assert_eq!(layers.toggle(0).toggle(0), layers);
+    }
+
+    #[test]
+    fn highest_and_lowest() {
+        let layers = RenderLayers::from_layers(&[3, 9, 20]);
+        assert_eq!(layers.lowest(), Some(3));
+        assert_eq!(layers.highest(), Some(20));
+        assert_eq!(RenderLayers::layer(31).lowest(), Some(31));
+        assert_eq!(RenderLayers::layer(31).highest(), Some(31));
+        assert_eq!(RenderLayers::layer(0).highest(), Some(0));
+        assert_eq!(RenderLayers::none().lowest(), None);
+        assert_eq!(RenderLayers::none().highest(), None);
+    }
+
+    #[test]
+    fn hash_groups_equal_masks() {
+        let mut groups = HashMap::<RenderLayers, Vec<&str>>::default();
+        groups.entry(RenderLayers::layer(1)).or_default().push("a");
+        groups
+            .entry(RenderLayers::from_layers(&[1, 2]).without(2))
+            .or_default()
+            .push("b");
+        groups.entry(RenderLayers::layer(2)).or_default().push("c");
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[&RenderLayers::layer(1)], vec!["a", "b"]);
+        assert_eq!(groups[&RenderLayers::layer(2)], vec!["c"]);
+    }
+
+    #[test]
+    fn removed_layers_compare_equal() {
+        assert_eq!(
+            RenderLayers::layer(0).with(31).without(31),
+            RenderLayers::layer(0)
+        );
+        assert_eq!(
+            RenderLayers::from_layers(&[0, 16]).without(16),
+            RenderLayers::default()
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(RenderLayers::none().to_string(), "{}");
+        assert_eq!(RenderLayers::layer(5).to_string(), "{5}");
+        assert_eq!(
+            RenderLayers::from_layers(&[0, 1, 2, 3, 7]).to_string(),
+            "{0-3,7}"
+        );
+        assert_eq!(
+            RenderLayers::from_layers(&[1, 4, 5, 9, 30, 31]).to_string(),
+            "{1,4-5,9,30-31}"
+        );
+        assert_eq!(RenderLayers::all().to_string(), "{0-31}");
+    }
+
+    #[test]
+    fn contains_all_and_any() {
+        let layers = RenderLayers::from_layers(&[1, 4, 9, 31]);
+        assert!(layers.contains_all(&[1, 4, 9]));
+        assert!(layers.contains_all(&[31]));
+        assert!(!layers.contains_all(&[1, 2]));
+        assert!(layers.contains_any(&[2, 9]));
+        assert!(!layers.contains_any(&[0, 2, 30]));
+
+        assert!(layers.contains_all(&[]), "vacuously contains no layers");
+        assert!(RenderLayers::none().contains_all(&[]));
+        assert!(!layers.contains_any(&[]), "can't contain any of no layers");
+    }
+
+    #[test]
+    fn intersection() {
+        let a = RenderLayers::from_layers(&[0, 3, 16, 31]);
+        let b = RenderLayers::from_layers(&[3, 9, 16, 31]);
+        assert_eq!(a.intersection(&b).collect::<Vec<_>>(), vec![3, 16, 31]);
+        assert_eq!(
+            a.intersection(&b).collect::<Vec<_>>(),
+            b.intersection(&a).collect::<Vec<_>>()
+        );
+        assert_eq!(a.intersection(&RenderLayers::none()).count(), 0);
+    }
+
+    #[test]
+    fn bits_roundtrip() {
+        let layers = RenderLayers::from_layers(&[0, 5, 31]);
+        assert_eq!(layers.bits(), 1 | 1 << 5 | 1 << 31);
+        assert_eq!(RenderLayers::from_bits(layers.bits()), layers);
+        assert_eq!(RenderLayers::from_bits(0), RenderLayers::none());
+        assert_eq!(RenderLayers::from_bits(u32::MAX), RenderLayers::all());
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        for layers in [
+            RenderLayers::none(),
+            RenderLayers::default(),
+            RenderLayers::from_layers(&[0, 3, 16, 31]),
+            RenderLayers::all(),
+        ] {
+            let serialized = ron::to_string(&layers).unwrap();
+            assert_eq!(ron::from_str::<RenderLayers>(&serialized).unwrap(), layers);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_past_last_layer() {
+        let _ = RenderLayers::none().with(RenderLayers::TOTAL_LAYERS as Layer);
+    }
+
+    #[test]
+    #[should_panic]
+    fn toggle_past_last_layer() {
+        let _ = RenderLayers::none().toggle(Layer::MAX);
+    }
+
+    #[test]
+    fn retain() {
+        let mut layers = RenderLayers::from_range(..);
+        layers.retain(|layer| layer % 2 == 0);
+        assert_eq!(layers.count(), 16);
+        assert!(layers.iter().all(|layer| layer % 2 == 0));
+
+        let mut layers = RenderLayers::from_layers(&[1, 15, 16, 31]);
+        layers.retain(|layer| layer < 16);
+        assert_eq!(layers, RenderLayers::from_layers(&[1, 15]));
+
+        let mut visited = Vec::new();
+        let mut layers = RenderLayers::from_layers(&[2, 7]);
+        layers.retain(|layer| {
+            visited.push(layer);
+            true
+        });
+        assert_eq!(visited, vec![2, 7]);
+        assert_eq!(layers, RenderLayers::from_layers(&[2, 7]));
+    }
+
+    #[test]
+    fn with_and_without_layers() {
+        let layers = RenderLayers::none().with_layers(&[0, 16, 31]);
+        assert_eq!(layers, RenderLayers::none().with(0).with(16).with(31));
+        assert_eq!(
+            layers.without_layers(&[0, 31]),
+            layers.without(0).without(31)
+        );
+        assert_eq!(layers.with_layers(&[]), layers);
+        assert_eq!(layers.without_layers(&[]), layers);
+        assert_eq!(layers.without_layers(&[1, 2]), layers);
+    }
+
+    #[test]
+    fn subsets() {
+        let a = RenderLayers::from_layers(&[1, 16]);
+        let b = RenderLayers::from_layers(&[1, 9, 16, 31]);
+        assert!(a.is_subset(&b));
+        assert!(!b.is_subset(&a));
+        assert!(b.is_superset(&a));
+        assert!(!a.is_superset(&b));
+        assert!(a.is_subset(&a) && a.is_superset(&a));
+        assert!(!RenderLayers::layer(31).is_subset(&RenderLayers::layer(30)));
+
+        let empty = RenderLayers::none();
+        assert!(empty.is_subset(&a));
+        assert!(empty.is_subset(&empty));
+        assert!(a.is_superset(&empty));
+        assert!(!a.is_subset(&empty));
+    }
+
+    #[test]
+    fn iter_rev() {
+        let layers = RenderLayers::from_layers(&[0, 5, 16, 31]);
+        assert_eq!(layers.iter().rev().collect::<Vec<_>>(), vec![31, 16, 5, 0]);
+
+        let mut iter = layers.iter();
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next_back(), Some(31));
+        assert_eq!(iter.next(), Some(5));
+        assert_eq!(iter.next_back(), Some(16));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        assert_eq!(RenderLayers::none().iter().next_back(), None);
+    }
+
+    #[test]
+    fn without_range() {
+        let layers = RenderLayers::from_layers(&[0, 3, 4, 5, 16, 31]);
+        assert_eq!(
+            layers.without_range(3..5),
+            RenderLayers::from_layers(&[0, 5, 16, 31])
+        );
+        assert_eq!(
+            layers.without_range(4..=16),
+            RenderLayers::from_layers(&[0, 3, 31])
+        );
+        assert_eq!(
+            layers.without_range(..4),
+            RenderLayers::from_layers(&[4, 5, 16, 31])
+        );
+        assert_eq!(
+            layers.without_range(16..),
+            RenderLayers::from_layers(&[0, 3, 4, 5])
+        );
+        assert_eq!(layers.without_range(6..16), layers);
+        assert_eq!(layers.without_range(7..7), layers);
+        assert!(layers.without_range(..).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn without_range_past_last_layer() {
+        let _ = RenderLayers::all().without_range(16..=32);
+    }
 }
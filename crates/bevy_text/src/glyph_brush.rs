@@ -52,6 +52,12 @@ impl GlyphBrush {
         Ok(section_glyphs)
     }
 
+    /// Positions `glyphs` and adds them to the font atlases, replacing the contents of
+    /// `positioned_glyphs`. Its allocation is reused, so it only grows when there are more glyphs
+    /// than it has ever held.
+    ///
+    /// If a glyph can't be added to a font atlas, `positioned_glyphs` is left holding the glyphs
+    /// positioned before it.
     #[allow(clippy::too_many_arguments)]
     pub fn process_glyphs(
         &self,
@@ -64,9 +70,11 @@ impl GlyphBrush {
         text_settings: &TextSettings,
         font_atlas_warning: &mut FontAtlasWarning,
         y_axis_orientation: YAxisOrientation,
-    ) -> Result<Vec<PositionedGlyph>, TextError> {
+        positioned_glyphs: &mut Vec<PositionedGlyph>,
+    ) -> Result<(), TextError> {
+        positioned_glyphs.clear();
         if glyphs.is_empty() {
-            return Ok(Vec::new());
+            return Ok(());
         }
 
         let sections_data = sections
@@ -86,7 +94,7 @@ impl GlyphBrush {
 
         let text_bounds = compute_text_bounds(&glyphs, |index| &sections_data[index].3);
 
-        let mut positioned_glyphs = Vec::new();
+        positioned_glyphs.reserve(glyphs.len());
         for sg in glyphs {
             let SectionGlyph {
                 section_index: _,
@@ -145,7 +153,7 @@ impl GlyphBrush {
                 });
             }
         }
-        Ok(positioned_glyphs)
+        Ok(())
     }
 
     pub fn add_font(&mut self, handle: Handle<Font>, font: FontArc) -> FontId {
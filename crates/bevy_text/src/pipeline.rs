@@ -1,4 +1,4 @@
-use ab_glyph::PxScale;
+use ab_glyph::{PxScale, ScaleFont};
 use bevy_asset::{Assets, Handle, HandleId};
 use bevy_ecs::component::Component;
 use bevy_ecs::system::Resource;
@@ -28,6 +28,11 @@ pub struct TextPipeline {
 pub struct TextLayoutInfo {
     pub glyphs: Vec<PositionedGlyph>,
     pub size: Vec2,
+    /// Whether the text didn't fully fit inside the bounds it was laid out in.
+    ///
+    /// This is set when the text extends past the bounds, or when whole lines were dropped
+    /// because they started below the bottom of the bounds.
+    pub overflowed: bool,
 }
 
 impl TextPipeline {
@@ -55,14 +60,61 @@ impl TextPipeline {
         font_atlas_warning: &mut FontAtlasWarning,
         y_axis_orientation: YAxisOrientation,
     ) -> Result<TextLayoutInfo, TextError> {
+        let mut text_layout_info = TextLayoutInfo::default();
+        self.queue_text_into(
+            fonts,
+            sections,
+            scale_factor,
+            text_alignment,
+            linebreak_behavior,
+            bounds,
+            font_atlas_set_storage,
+            texture_atlases,
+            textures,
+            text_settings,
+            font_atlas_warning,
+            y_axis_orientation,
+            &mut text_layout_info,
+        )?;
+        Ok(text_layout_info)
+    }
+
+    /// Like [`queue_text`](Self::queue_text), but lays the text out into an existing
+    /// [`TextLayoutInfo`], reusing the allocation of its glyphs.
+    ///
+    /// `text_layout_info` is left unchanged if a font isn't loaded. If a glyph can't be added to
+    /// a font atlas, it is left holding the glyphs processed before it.
+    #[allow(clippy::too_many_arguments)]
+    pub fn queue_text_into(
+        &mut self,
+        fonts: &Assets<Font>,
+        sections: &[TextSection],
+        scale_factor: f64,
+        text_alignment: TextAlignment,
+        linebreak_behavior: BreakLineOn,
+        bounds: Vec2,
+        font_atlas_set_storage: &mut Assets<FontAtlasSet>,
+        texture_atlases: &mut Assets<TextureAtlas>,
+        textures: &mut Assets<Image>,
+        text_settings: &TextSettings,
+        font_atlas_warning: &mut FontAtlasWarning,
+        y_axis_orientation: YAxisOrientation,
+        text_layout_info: &mut TextLayoutInfo,
+    ) -> Result<(), TextError> {
         let mut scaled_fonts = Vec::with_capacity(sections.len());
+        // Empty sections produce no glyphs, so they are skipped. This maps the remaining
+        // sections back to their index in the `Text`.
+        let mut section_indices = Vec::with_capacity(sections.len());
         let sections = sections
             .iter()
-            .map(|section| {
+            .enumerate()
+            .filter(|(_, section)| !section.value.is_empty())
+            .map(|(index, section)| {
                 let font = fonts
                     .get(&section.style.font)
                     .ok_or(TextError::NoSuchFont)?;
                 let font_id = self.get_or_insert_font_id(&section.style.font, font);
+                section_indices.push(index);
                 let font_size = scale_value(section.style.font_size, scale_factor);
 
                 scaled_fonts.push(ab_glyph::Font::as_scaled(&font.font, font_size));
@@ -81,13 +133,54 @@ impl TextPipeline {
             self.brush
                 .compute_glyphs(&sections, bounds, text_alignment, linebreak_behavior)?;
 
+        // Lines that start below the bottom of the bounds are dropped whole, so the text was cut
+        // short if the last glyph isn't the last visible character. Control characters such as
+        // `\n` never produce glyphs.
+        let last_visible = sections
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(index, section)| {
+                section
+                    .text
+                    .char_indices()
+                    .rfind(|(_, c)| !c.is_control())
+                    .map(|(byte_index, _)| (index, byte_index))
+            });
+
         if section_glyphs.is_empty() {
-            return Ok(TextLayoutInfo::default());
+            // Bounds too short for even the first line drop all of the text
+            text_layout_info.glyphs.clear();
+            text_layout_info.size = Vec2::ZERO;
+            text_layout_info.overflowed = last_visible.is_some();
+            return Ok(());
         }
 
         let size = compute_text_bounds(&section_glyphs, |index| &scaled_fonts[index]).size();
 
-        let glyphs = self.brush.process_glyphs(
+        // Whitespace at the end of a wrapped line is allowed to hang past the bounds, so only
+        // the other glyphs count towards overflowing horizontally.
+        let mut min_x = f32::MAX;
+        let mut max_x = f32::MIN;
+        for sg in &section_glyphs {
+            min_x = min_x.min(sg.glyph.position.x);
+            let is_whitespace = sections[sg.section_index].text[sg.byte_index..]
+                .chars()
+                .next()
+                .is_some_and(char::is_whitespace);
+            if !is_whitespace {
+                let h_advance = scaled_fonts[sg.section_index].h_advance(sg.glyph.id);
+                max_x = max_x.max(sg.glyph.position.x + h_advance);
+            }
+        }
+
+        let last_glyph = section_glyphs
+            .last()
+            .map(|glyph| (glyph.section_index, glyph.byte_index));
+        let overflowed =
+            bounds.x < max_x - min_x || bounds.y < size.y || last_glyph != last_visible;
+
+        let result = self.brush.process_glyphs(
             section_glyphs,
             &sections,
             font_atlas_set_storage,
@@ -97,9 +190,16 @@ impl TextPipeline {
             text_settings,
             font_atlas_warning,
             y_axis_orientation,
-        )?;
+            &mut text_layout_info.glyphs,
+        );
+        for glyph in &mut text_layout_info.glyphs {
+            glyph.section_index = section_indices[glyph.section_index];
+        }
+        result?;
 
-        Ok(TextLayoutInfo { glyphs, size })
+        text_layout_info.size = size;
+        text_layout_info.overflowed = overflowed;
+        Ok(())
     }
 
     pub fn create_text_measure(
@@ -114,6 +214,7 @@ impl TextPipeline {
         let mut scaled_fonts = Vec::with_capacity(sections.len());
         let sections = sections
             .iter()
+            .filter(|section| !section.value.is_empty())
             .enumerate()
             .map(|(i, section)| {
                 let font = fonts
@@ -221,3 +322,227 @@ impl TextMeasureInfo {
         self.compute_size_from_section_texts(&sections, bounds)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+    use bevy_asset::{AddAsset, AssetPlugin, Assets};
+    use bevy_math::Vec2;
+    use bevy_render::texture::Image;
+    use bevy_sprite::TextureAtlas;
+
+    use crate::{
+        BreakLineOn, Font, FontAtlasSet, FontAtlasWarning, TextAlignment, TextLayoutInfo,
+        TextPipeline, TextSection, TextSettings, TextStyle, YAxisOrientation,
+    };
+
+    struct TestContext {
+        pipeline: TextPipeline,
+        fonts: Assets<Font>,
+        font_atlas_sets: Assets<FontAtlasSet>,
+        texture_atlases: Assets<TextureAtlas>,
+        textures: Assets<Image>,
+        style: TextStyle,
+        // Keeps the asset server alive while handles are dropped.
+        _app: App,
+    }
+
+    impl TestContext {
+        fn new() -> Self {
+            let mut app = App::new();
+            app.add_plugins(AssetPlugin::default())
+                .add_asset::<Font>()
+                .add_asset::<FontAtlasSet>()
+                .add_asset::<TextureAtlas>()
+                .add_asset::<Image>();
+            let mut fonts = app.world.remove_resource::<Assets<Font>>().unwrap();
+            let font = fonts
+                .add(Font::try_from_bytes(include_bytes!("FiraMono-subset.ttf").to_vec()).unwrap());
+            Self {
+                pipeline: TextPipeline::default(),
+                fonts,
+                font_atlas_sets: app.world.remove_resource().unwrap(),
+                texture_atlases: app.world.remove_resource().unwrap(),
+                textures: app.world.remove_resource().unwrap(),
+                style: TextStyle {
+                    font,
+                    font_size: 20.,
+                    ..Default::default()
+                },
+                _app: app,
+            }
+        }
+
+        fn section(&self, value: &str) -> TextSection {
+            TextSection::new(value, self.style.clone())
+        }
+
+        fn queue(&mut self, value: &str, bounds: Vec2) -> TextLayoutInfo {
+            let sections = [self.section(value)];
+            self.queue_sections(&sections, bounds)
+        }
+
+        fn queue_sections(&mut self, sections: &[TextSection], bounds: Vec2) -> TextLayoutInfo {
+            let mut info = TextLayoutInfo::default();
+            self.queue_into(sections, bounds, &mut info);
+            info
+        }
+
+        fn queue_into(
+            &mut self,
+            sections: &[TextSection],
+            bounds: Vec2,
+            info: &mut TextLayoutInfo,
+        ) {
+            self.pipeline
+                .queue_text_into(
+                    &self.fonts,
+                    sections,
+                    1.,
+                    TextAlignment::Left,
+                    BreakLineOn::WordBoundary,
+                    bounds,
+                    &mut self.font_atlas_sets,
+                    &mut self.texture_atlases,
+                    &mut self.textures,
+                    &TextSettings::default(),
+                    &mut FontAtlasWarning::default(),
+                    YAxisOrientation::TopToBottom,
+                    info,
+                )
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn overflow_is_reported() {
+        let mut ctx = TestContext::new();
+        let bounds = Vec2::new(200., 30.);
+
+        let fitting = ctx.queue("Hello", bounds);
+        assert!(!fitting.glyphs.is_empty());
+        assert!(!fitting.overflowed);
+
+        let overflowing = ctx.queue("Hello\nHello\nHello\nHello", bounds);
+        assert!(overflowing.overflowed);
+
+        // The second line starts below bounds that only fit the first, so it is dropped and the
+        // laid out size still fits.
+        let one_line = Vec2::new(200., fitting.size.y);
+        let dropped = ctx.queue("Hello\nHello", one_line);
+        assert_eq!(dropped.glyphs.len(), fitting.glyphs.len());
+        assert!(dropped.size.y <= one_line.y);
+        assert!(dropped.overflowed);
+    }
+
+    #[test]
+    fn zero_height_bounds_overflow() {
+        let mut ctx = TestContext::new();
+        let info = ctx.queue("Hello", Vec2::new(200., 0.));
+        assert!(info.glyphs.is_empty());
+        assert!(info.overflowed);
+
+        // There's nothing to drop from empty text
+        assert!(!ctx.queue("", Vec2::new(200., 0.)).overflowed);
+    }
+
+    #[test]
+    fn trailing_whitespace_of_wrapped_lines_does_not_overflow() {
+        let mut ctx = TestContext::new();
+        let unbounded = Vec2::splat(f32::INFINITY);
+        let word_width = ctx.queue("Hello", unbounded).size.x;
+        let all_glyphs = ctx.queue("Hello Hello", unbounded).glyphs.len();
+
+        // Only just wide enough for one word, so the space ends the first line
+        let wrapped = ctx.queue("Hello Hello", Vec2::new(word_width + 1., 1000.));
+        assert_eq!(wrapped.glyphs.len(), all_glyphs);
+        assert!(!wrapped.overflowed);
+
+        // A word that doesn't fit on its own line still overflows
+        let narrow = ctx.queue("Hello Hello", Vec2::new(word_width / 2., 1000.));
+        assert!(narrow.overflowed);
+    }
+
+    #[test]
+    fn fitting_ui_bounds_do_not_overflow() {
+        let mut ctx = TestContext::new();
+        let text = "Hello Hello Hello\nHello Hello\n";
+
+        // UI nodes are laid out in their measured size, rounded up to whole pixels.
+        let unbounded = ctx.queue(text, Vec2::splat(f32::INFINITY));
+        assert!(!unbounded.overflowed);
+        let wrapped = ctx.queue(text, Vec2::new(unbounded.size.x / 2., f32::INFINITY));
+        assert!(!wrapped.overflowed);
+
+        for size in [unbounded.size, wrapped.size] {
+            let info = ctx.queue(text, size.ceil());
+            assert_eq!(info.size, size);
+            assert!(!info.overflowed);
+        }
+    }
+
+    #[test]
+    fn relayout_reuses_glyph_allocation() {
+        let mut ctx = TestContext::new();
+        let bounds = Vec2::splat(f32::INFINITY);
+        let mut info = TextLayoutInfo::default();
+        ctx.queue_into(&[ctx.section("Score: 0")], bounds, &mut info);
+        let glyphs = info.glyphs.as_ptr();
+        let capacity = info.glyphs.capacity();
+
+        // Editing the last character keeps the glyph count, so the allocation is reused
+        for digit in '1'..='9' {
+            ctx.queue_into(
+                &[ctx.section(&format!("Score: {digit}"))],
+                bounds,
+                &mut info,
+            );
+            assert_eq!(info.glyphs.len(), 7);
+            assert_eq!(info.glyphs.as_ptr(), glyphs);
+            assert_eq!(info.glyphs.capacity(), capacity);
+        }
+        assert_eq!(info.glyphs.last().unwrap().byte_index, 7);
+    }
+
+    #[test]
+    fn empty_sections_are_skipped() {
+        let mut ctx = TestContext::new();
+        let with_empty = [
+            ctx.section(""),
+            ctx.section("Hello "),
+            ctx.section(""),
+            ctx.section("World"),
+            ctx.section(""),
+        ];
+        let without_empty = [ctx.section("Hello "), ctx.section("World")];
+
+        let measure = |ctx: &mut TestContext, sections: &[TextSection]| {
+            ctx.pipeline
+                .create_text_measure(
+                    &ctx.fonts,
+                    sections,
+                    1.,
+                    TextAlignment::Left,
+                    BreakLineOn::WordBoundary,
+                )
+                .unwrap()
+        };
+        let a = measure(&mut ctx, &with_empty);
+        let b = measure(&mut ctx, &without_empty);
+        assert_eq!(a.min_width_content_size, b.min_width_content_size);
+        assert_eq!(a.max_width_content_size, b.max_width_content_size);
+
+        let bounds = Vec2::splat(f32::INFINITY);
+        let a = ctx.queue_sections(&with_empty, bounds);
+        let b = ctx.queue_sections(&without_empty, bounds);
+        assert_eq!(a.size, b.size);
+        assert_eq!(a.glyphs.len(), b.glyphs.len());
+        // Glyphs still refer to their section in the original list
+        assert_eq!(a.glyphs.first().unwrap().section_index, 1);
+        assert_eq!(a.glyphs.last().unwrap().section_index, 3);
+        for (a, b) in a.glyphs.iter().zip(&b.glyphs) {
+            assert_eq!(a.position, b.position);
+            assert_eq!(a.byte_index, b.byte_index);
+        }
+    }
+}
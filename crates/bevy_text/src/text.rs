@@ -113,6 +113,30 @@ impl Text {
         self.linebreak_behavior = BreakLineOn::NoWrap;
         self
     }
+
+    /// Appends a [`TextSection`] to the end of this [`Text`].
+    ///
+    /// Mutating a [`Text`] through change detection schedules a new measure and layout,
+    /// so the new section appears on the next layout update.
+    pub fn push_section(&mut self, section: TextSection) {
+        self.sections.push(section);
+    }
+
+    /// Inserts a [`TextSection`] at position `index`, shifting all sections after it.
+    ///
+    /// # Panics
+    /// Panics if `index > sections.len()`.
+    pub fn insert_section(&mut self, index: usize, section: TextSection) {
+        self.sections.insert(index, section);
+    }
+
+    /// Removes and returns the [`TextSection`] at position `index`, shifting all sections after it.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn remove_section(&mut self, index: usize) -> TextSection {
+        self.sections.remove(index)
+    }
 }
 
 #[derive(Debug, Default, Clone, Reflect)]
@@ -217,3 +241,27 @@ impl From<BreakLineOn> for glyph_brush_layout::BuiltInLineBreaker {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Text, TextSection, TextStyle};
+
+    #[test]
+    fn section_editing() {
+        let mut text = Text::from_section("a", TextStyle::default());
+        text.push_section(TextSection::new("c", TextStyle::default()));
+        text.insert_section(1, TextSection::new("b", TextStyle::default()));
+        assert_eq!(
+            text.sections
+                .iter()
+                .map(|section| section.value.as_str())
+                .collect::<Vec<_>>(),
+            vec!["a", "b", "c"]
+        );
+
+        let removed = text.remove_section(0);
+        assert_eq!(removed.value, "a");
+        assert_eq!(text.sections.len(), 2);
+        assert_eq!(text.sections[0].value, "b");
+    }
+}
@@ -1,7 +1,7 @@
 use bevy_asset::Assets;
 use bevy_ecs::{
     bundle::Bundle,
-    change_detection::{DetectChanges, Ref},
+    change_detection::{DetectChanges, DetectChangesMut, Ref},
     component::Component,
     entity::Entity,
     event::EventReader,
@@ -19,7 +19,7 @@ use bevy_render::{
 };
 use bevy_sprite::{Anchor, ExtractedSprite, ExtractedSprites, TextureAtlas};
 use bevy_transform::prelude::{GlobalTransform, Transform};
-use bevy_utils::HashSet;
+use bevy_utils::{tracing::error, HashSet};
 use bevy_window::{PrimaryWindow, Window, WindowScaleFactorChanged};
 
 use crate::{
@@ -182,7 +182,7 @@ pub fn update_text2d_layout(
                 scale_value(bounds.size.y, scale_factor),
             );
 
-            match text_pipeline.queue_text(
+            match text_pipeline.queue_text_into(
                 &fonts,
                 &text.sections,
                 scale_factor,
@@ -195,6 +195,8 @@ pub fn update_text2d_layout(
                 text_settings.as_ref(),
                 &mut font_atlas_warning,
                 YAxisOrientation::BottomToTop,
+                // Only flagged as changed below when the layout was written
+                text_layout_info.bypass_change_detection(),
             ) {
                 Err(TextError::NoSuchFont) => {
                     // There was an error processing the text layout, let's add this entity to the
@@ -202,9 +204,12 @@ pub fn update_text2d_layout(
                     queue.insert(entity);
                 }
                 Err(e @ TextError::FailedToAddGlyph(_)) => {
-                    panic!("Fatal error when processing text: {e}.");
+                    // Only this entity is affected. The error won't resolve itself, so the text
+                    // is processed again the next time it changes.
+                    error!("Error when processing text: {e}.");
+                    text_layout_info.set_changed();
                 }
-                Ok(info) => *text_layout_info = info,
+                Ok(()) => text_layout_info.set_changed(),
             }
         }
     }
@@ -1,7 +1,7 @@
 use crate::{ContentSize, FixedMeasure, Measure, Node, UiScale};
 use bevy_asset::Assets;
 use bevy_ecs::{
-    prelude::{Component, DetectChanges},
+    prelude::{Component, DetectChanges, DetectChangesMut},
     query::With,
     reflect::ReflectComponent,
     system::{Local, Query, Res, ResMut},
@@ -15,9 +15,17 @@ use bevy_text::{
     BreakLineOn, Font, FontAtlasSet, FontAtlasWarning, Text, TextError, TextLayoutInfo,
     TextMeasureInfo, TextPipeline, TextSettings, YAxisOrientation,
 };
+use bevy_utils::tracing::error;
 use bevy_window::{PrimaryWindow, Window};
+use std::sync::Arc;
 use taffy::style::AvailableSpace;
 
+/// The maximum number of frames to wait between attempts to process text whose font isn't loaded.
+const MAX_RETRY_INTERVAL: u32 = 32;
+
+/// The number of scale factors for which a text node keeps its measured size cached.
+const MAX_CACHED_MEASURES: usize = 4;
+
 /// Text system flags
 ///
 /// Used internally by [`measure_text_system`] and [`text_system`] to schedule text for processing.
@@ -28,6 +36,15 @@ pub struct TextFlags {
     needs_new_measure_func: bool,
     /// If set the text will be recomputed
     needs_recompute: bool,
+    /// Number of frames between retries while the text's font isn't loaded.
+    /// Doubles after every failed retry, up to `MAX_RETRY_INTERVAL`.
+    retry_interval: u32,
+    /// Frames remaining until the next retry.
+    retry_countdown: u32,
+    /// Measurements of the text at recently used scale factors, most recently used last.
+    /// Cleared whenever the text changes. Shared with the node's current [`TextMeasure`].
+    #[reflect(ignore)]
+    measure_cache: Vec<(f64, Arc<TextMeasureInfo>)>,
 }
 
 impl Default for TextFlags {
@@ -35,13 +52,62 @@ impl Default for TextFlags {
         Self {
             needs_new_measure_func: true,
             needs_recompute: true,
+            retry_interval: 0,
+            retry_countdown: 0,
+            measure_cache: Vec::new(),
+        }
+    }
+}
+
+impl TextFlags {
+    /// Delays the next retry after the text's font wasn't loaded, doubling the interval since
+    /// the previous attempt.
+    fn back_off(&mut self) {
+        self.retry_interval = (self.retry_interval * 2).clamp(1, MAX_RETRY_INTERVAL);
+        self.retry_countdown = self.retry_interval - 1;
+    }
+
+    /// Clears any pending retry delay after the text was processed successfully.
+    fn reset_back_off(&mut self) {
+        self.retry_interval = 0;
+        self.retry_countdown = 0;
+    }
+
+    /// Returns the cached measurement of the text at `scale_factor`, if there is one.
+    fn cached_measure(&self, scale_factor: f64) -> Option<&Arc<TextMeasureInfo>> {
+        #[allow(clippy::float_cmp)]
+        self.measure_cache
+            .iter()
+            .find(|(cached_scale_factor, _)| *cached_scale_factor == scale_factor)
+            .map(|(_, measure)| measure)
+    }
+
+    /// Caches the measurement of the text at `scale_factor`, evicting the least recently used
+    /// measurement if the cache is full.
+    fn cache_measure(&mut self, scale_factor: f64, measure: Arc<TextMeasureInfo>) {
+        #[allow(clippy::float_cmp)]
+        self.measure_cache
+            .retain(|(cached_scale_factor, _)| *cached_scale_factor != scale_factor);
+        if self.measure_cache.len() == MAX_CACHED_MEASURES {
+            self.measure_cache.remove(0);
+        }
+        self.measure_cache.push((scale_factor, measure));
+    }
+
+    /// Advances the retry countdown by one frame, returning `true` if a retry is due.
+    fn tick_retry(&mut self) -> bool {
+        if self.retry_countdown == 0 {
+            true
+        } else {
+            self.retry_countdown -= 1;
+            false
         }
     }
 }
 
 #[derive(Clone)]
 pub struct TextMeasure {
-    pub info: TextMeasureInfo,
+    pub info: Arc<TextMeasureInfo>,
 }
 
 impl Measure for TextMeasure {
@@ -83,14 +149,26 @@ fn create_text_measure(
     mut content_size: Mut<ContentSize>,
     mut text_flags: Mut<TextFlags>,
 ) {
-    match text_pipeline.create_text_measure(
-        fonts,
-        &text.sections,
-        scale_factor,
-        text.alignment,
-        text.linebreak_behavior,
-    ) {
+    if text.is_changed() {
+        text_flags.measure_cache.clear();
+    }
+
+    let measure = match text_flags.cached_measure(scale_factor) {
+        Some(measure) => Ok(measure.clone()),
+        None => text_pipeline
+            .create_text_measure(
+                fonts,
+                &text.sections,
+                scale_factor,
+                text.alignment,
+                text.linebreak_behavior,
+            )
+            .map(Arc::new),
+    };
+
+    match measure {
         Ok(measure) => {
+            text_flags.cache_measure(scale_factor, measure.clone());
             if text.linebreak_behavior == BreakLineOn::NoWrap {
                 content_size.set(FixedMeasure {
                     size: measure.max_width_content_size,
@@ -102,13 +180,18 @@ fn create_text_measure(
             // Text measure func created successfully, so set `TextFlags` to schedule a recompute
             text_flags.needs_new_measure_func = false;
             text_flags.needs_recompute = true;
+            text_flags.reset_back_off();
         }
         Err(TextError::NoSuchFont) => {
-            // Try again next frame
+            // Try again in a later frame
             text_flags.needs_new_measure_func = true;
+            text_flags.back_off();
         }
         Err(e @ TextError::FailedToAddGlyph(_)) => {
-            panic!("Fatal error when processing text: {e}.");
+            // Only this text node is affected. The error won't resolve itself, so the text is
+            // measured again the next time it changes.
+            error!("Error when processing text: {e}.");
+            text_flags.needs_new_measure_func = false;
         }
     };
 }
@@ -133,8 +216,8 @@ pub fn measure_text_system(
     #[allow(clippy::float_cmp)]
     if *last_scale_factor == scale_factor {
         // scale factor unchanged, only create new measure funcs for modified text
-        for (text, content_size, text_flags) in text_query.iter_mut() {
-            if text.is_changed() || text_flags.needs_new_measure_func {
+        for (text, content_size, mut text_flags) in text_query.iter_mut() {
+            if text.is_changed() || (text_flags.needs_new_measure_func && text_flags.tick_retry()) {
                 create_text_measure(
                     &fonts,
                     &mut text_pipeline,
@@ -188,7 +271,7 @@ fn queue_text(
             node.physical_size(scale_factor, 1.)
         };
 
-        match text_pipeline.queue_text(
+        match text_pipeline.queue_text_into(
             fonts,
             &text.sections,
             scale_factor,
@@ -201,17 +284,25 @@ fn queue_text(
             text_settings,
             font_atlas_warning,
             YAxisOrientation::TopToBottom,
+            // Only flagged as changed below when the layout was written
+            text_layout_info.bypass_change_detection(),
         ) {
             Err(TextError::NoSuchFont) => {
-                // There was an error processing the text layout, try again next frame
+                // There was an error processing the text layout, try again in a later frame
                 text_flags.needs_recompute = true;
+                text_flags.back_off();
             }
             Err(e @ TextError::FailedToAddGlyph(_)) => {
-                panic!("Fatal error when processing text: {e}.");
+                // Only this text node is affected. The error won't resolve itself, so the text
+                // is processed again the next time it or its node changes.
+                error!("Error when processing text: {e}.");
+                text_layout_info.set_changed();
+                text_flags.needs_recompute = false;
             }
-            Ok(info) => {
-                *text_layout_info = info;
+            Ok(()) => {
+                text_layout_info.set_changed();
                 text_flags.needs_recompute = false;
+                text_flags.reset_back_off();
             }
         }
     }
@@ -248,8 +339,13 @@ pub fn text_system(
 
     if *last_scale_factor == scale_factor {
         // Scale factor unchanged, only recompute text for modified text nodes
-        for (node, text, text_layout_info, text_flags) in text_query.iter_mut() {
-            if node.is_changed() || text_flags.needs_recompute {
+        for (node, text, text_layout_info, mut text_flags) in text_query.iter_mut() {
+            // Retries of text waiting on a new measure func are paced by `measure_text_system`
+            if node.is_changed()
+                || (text_flags.needs_recompute
+                    && !text_flags.needs_new_measure_func
+                    && text_flags.tick_retry())
+            {
                 queue_text(
                     &fonts,
                     &mut text_pipeline,
@@ -288,3 +384,224 @@ pub fn text_system(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{measure_text_system, text_system, TextFlags};
+    use crate::{ContentSize, Node, UiScale};
+    use bevy_app::{App, Update};
+    use bevy_asset::{AddAsset, AssetPlugin, Assets, Handle, HandleId};
+    use bevy_ecs::{entity::Entity, schedule::IntoSystemConfigs};
+    use bevy_math::Vec2;
+    use bevy_render::texture::Image;
+    use bevy_sprite::TextureAtlas;
+    use bevy_text::{
+        BreakLineOn, Font, FontAtlasSet, FontAtlasWarning, Text, TextAlignment, TextLayoutInfo,
+        TextMeasureInfo, TextPipeline, TextSection, TextSettings, TextStyle,
+    };
+    use std::sync::Arc;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(AssetPlugin::default())
+            .add_asset::<Font>()
+            .add_asset::<FontAtlasSet>()
+            .add_asset::<TextureAtlas>()
+            .add_asset::<Image>()
+            .init_resource::<UiScale>()
+            .init_resource::<TextPipeline>()
+            .init_resource::<TextSettings>()
+            .init_resource::<FontAtlasWarning>()
+            .add_systems(Update, (measure_text_system, text_system).chain());
+        app
+    }
+
+    fn spawn_text(app: &mut App, font: Handle<Font>) -> Entity {
+        app.world
+            .spawn((
+                Node::default(),
+                Text::from_section(
+                    "Hello",
+                    TextStyle {
+                        font,
+                        ..Default::default()
+                    },
+                ),
+                ContentSize::default(),
+                TextFlags::default(),
+                TextLayoutInfo::default(),
+            ))
+            .id()
+    }
+
+    fn test_font() -> Font {
+        Font::try_from_bytes(include_bytes!("../../../bevy_text/src/FiraMono-subset.ttf").to_vec())
+            .unwrap()
+    }
+
+    #[test]
+    fn missing_font_retries_back_off() {
+        let mut app = test_app();
+        let font = Handle::<Font>::weak(HandleId::random::<Font>());
+        let entity = spawn_text(&mut app, font.clone());
+
+        // The font never loads, so every retry fails and the next one is scheduled further out
+        let mut retry_frames = Vec::new();
+        for frame in 1..=130 {
+            app.update();
+            let flags = app.world.get::<TextFlags>(entity).unwrap();
+            assert!(flags.needs_new_measure_func);
+            if flags.retry_countdown == flags.retry_interval - 1 {
+                retry_frames.push(frame);
+            }
+        }
+        // Retries are 1, 2, 4, .. frames apart, capped at `MAX_RETRY_INTERVAL`
+        assert_eq!(retry_frames, vec![1, 2, 4, 8, 16, 32, 64, 96, 128]);
+
+        // Once the font loads the text is processed at the next scheduled retry
+        let _font = app
+            .world
+            .resource_mut::<Assets<Font>>()
+            .set(font, test_font());
+        for _ in 131..160 {
+            app.update();
+            assert!(
+                app.world
+                    .get::<TextFlags>(entity)
+                    .unwrap()
+                    .needs_new_measure_func
+            );
+        }
+        app.update();
+        let flags = app.world.get::<TextFlags>(entity).unwrap();
+        assert!(!flags.needs_new_measure_func);
+        assert!(!flags.needs_recompute);
+        assert_eq!(flags.retry_interval, 0);
+    }
+
+    #[test]
+    fn measures_are_cached_per_scale_factor() {
+        let mut app = test_app();
+        let font = app.world.resource_mut::<Assets<Font>>().add(test_font());
+        let entity = spawn_text(&mut app, font);
+
+        let cached_measure = |app: &App, scale_factor: f64| {
+            let flags = app.world.get::<TextFlags>(entity).unwrap();
+            flags.cached_measure(scale_factor).unwrap().clone()
+        };
+
+        app.update();
+        let measure_1 = cached_measure(&app, 1.);
+        app.world.resource_mut::<UiScale>().scale = 2.;
+        app.update();
+        let measure_2 = cached_measure(&app, 2.);
+        assert!(!Arc::ptr_eq(&measure_1, &measure_2));
+
+        // Switching back and forth reuses the measures from the first visit
+        for scale_factor in [1., 2., 1., 2.] {
+            app.world.resource_mut::<UiScale>().scale = scale_factor;
+            app.update();
+            assert!(Arc::ptr_eq(&cached_measure(&app, 1.), &measure_1));
+            assert!(Arc::ptr_eq(&cached_measure(&app, 2.), &measure_2));
+        }
+
+        // Changing the text invalidates the cache
+        app.world.get_mut::<Text>(entity).unwrap().sections[0]
+            .value
+            .push('!');
+        app.update();
+        let flags = app.world.get::<TextFlags>(entity).unwrap();
+        assert_eq!(flags.measure_cache.len(), 1);
+        assert!(!Arc::ptr_eq(&cached_measure(&app, 2.), &measure_2));
+    }
+
+    #[test]
+    fn pushed_section_is_measured_and_laid_out() {
+        let mut app = test_app();
+        let font = app.world.resource_mut::<Assets<Font>>().add(test_font());
+        let entity = spawn_text(&mut app, font.clone());
+        app.world.get_mut::<Node>(entity).unwrap().calculated_size = Vec2::splat(500.);
+        app.update();
+        let measure = app
+            .world
+            .get::<TextFlags>(entity)
+            .unwrap()
+            .cached_measure(1.)
+            .unwrap()
+            .clone();
+
+        app.world
+            .get_mut::<Text>(entity)
+            .unwrap()
+            .push_section(TextSection::new(
+                " World",
+                TextStyle {
+                    font,
+                    ..Default::default()
+                },
+            ));
+        app.update();
+
+        // The text was measured again and the new section is in the layout
+        let flags = app.world.get::<TextFlags>(entity).unwrap();
+        assert!(!Arc::ptr_eq(flags.cached_measure(1.).unwrap(), &measure));
+        assert!(!flags.needs_recompute);
+        let layout = app.world.get::<TextLayoutInfo>(entity).unwrap();
+        assert!(layout.glyphs.iter().any(|glyph| glyph.section_index == 1));
+    }
+
+    #[test]
+    fn emptied_text_clears_layout() {
+        let mut app = test_app();
+        let font = app.world.resource_mut::<Assets<Font>>().add(test_font());
+        let entity = spawn_text(&mut app, font);
+        app.world.get_mut::<Node>(entity).unwrap().calculated_size = Vec2::splat(500.);
+        app.update();
+        assert!(!app
+            .world
+            .get::<TextLayoutInfo>(entity)
+            .unwrap()
+            .glyphs
+            .is_empty());
+
+        app.world.get_mut::<Text>(entity).unwrap().sections[0]
+            .value
+            .clear();
+        app.update();
+
+        let layout = app.world.get::<TextLayoutInfo>(entity).unwrap();
+        assert!(layout.glyphs.is_empty());
+        assert_eq!(layout.size, Vec2::ZERO);
+        assert!(!app.world.get::<TextFlags>(entity).unwrap().needs_recompute);
+    }
+
+    #[test]
+    fn least_recently_used_measure_is_evicted() {
+        let mut flags = TextFlags::default();
+        for scale_factor in 1..=super::MAX_CACHED_MEASURES {
+            flags.cache_measure(scale_factor as f64, Arc::new(test_measure()));
+        }
+        // Using a measure again makes it the most recently used
+        flags.cache_measure(1., Arc::new(test_measure()));
+
+        flags.cache_measure(
+            (super::MAX_CACHED_MEASURES + 1) as f64,
+            Arc::new(test_measure()),
+        );
+        assert!(flags.cached_measure(1.).is_some());
+        assert!(flags.cached_measure(2.).is_none());
+        assert_eq!(flags.measure_cache.len(), super::MAX_CACHED_MEASURES);
+    }
+
+    fn test_measure() -> TextMeasureInfo {
+        TextMeasureInfo {
+            fonts: Vec::new(),
+            scaled_fonts: Vec::new(),
+            sections: Vec::new(),
+            text_alignment: TextAlignment::Left,
+            linebreak_behaviour: BreakLineOn::WordBoundary.into(),
+            min_width_content_size: Vec2::ZERO,
+            max_width_content_size: Vec2::ZERO,
+        }
+    }
+}